@@ -358,14 +358,14 @@ impl From<serde_json::error::Error> for ConversionError {
 }
 
 impl From<std::str::Utf8Error> for ConversionError {
-    fn from(_err: std::str::Utf8Error) -> Self {
-        Self::from("UTF-8 decoding error")
+    fn from(err: std::str::Utf8Error) -> Self {
+        Self::from_msg_err("UTF-8 decoding error", err)
     }
 }
 
 impl From<std::string::FromUtf8Error> for ConversionError {
-    fn from(_err: std::string::FromUtf8Error) -> Self {
-        Self::from("UTF-8 decoding error")
+    fn from(err: std::string::FromUtf8Error) -> Self {
+        Self::from_msg_err("UTF-8 decoding error", err)
     }
 }
 
@@ -404,3 +404,25 @@ impl From<UnexpectedError> for EncryptionError {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conversion_error_keeps_utf8_source() {
+        let bytes = vec![0x66, 0xff];
+        let err = ConversionError::from(std::str::from_utf8(&bytes).unwrap_err());
+        assert_eq!(err.to_string(), "Conversion error: UTF-8 decoding error");
+        assert!(err
+            .source()
+            .and_then(|source| source.downcast_ref::<std::str::Utf8Error>())
+            .is_some());
+
+        let err = ConversionError::from(String::from_utf8(bytes).unwrap_err());
+        assert!(err
+            .source()
+            .and_then(|source| source.downcast_ref::<std::string::FromUtf8Error>())
+            .is_some());
+    }
+}